@@ -1,15 +1,25 @@
-use crossterm::{
-    event,
-    style::{Print, Stylize},
-    terminal, ExecutableCommand,
-};
+mod config;
+mod error;
+mod fan;
+mod gpu;
+mod ui;
+
+use config::Config as AppConfig;
+use crossterm::{event, terminal, ExecutableCommand};
+use fan::FanController;
 use log::{info, error, LevelFilter};
 use simplelog::{Config, WriteLogger, TermLogger, TerminalMode, CombinedLogger};
 use std::fs::File;
-use std::io::{self, Write};
-use std::process::Command;
-use std::str;
+use std::io;
 use std::time::Duration;
+use ui::Dashboard;
+
+/// Parses a `--gpu <index>` filter from the command line, if present.
+fn parse_gpu_filter() -> Option<usize> {
+    let args: Vec<String> = std::env::args().collect();
+    let flag_pos = args.iter().position(|a| a == "--gpu")?;
+    args.get(flag_pos + 1)?.parse::<usize>().ok()
+}
 
 fn init_logger() {
     CombinedLogger::init(
@@ -29,107 +39,111 @@ fn init_logger() {
     ).unwrap();
 }
 
-fn get_gpu_temperature() -> Result<f32, String> {
-    let output = Command::new("nvidia-smi")
-        .arg("--query-gpu=temperature.gpu")
-        .arg("--format=csv,noheader,nounits")
-        .output()
-        .map_err(|e| format!("Failed to execute command: {}", e))?;
-
-    if !output.status.success() {
-        return Err(format!("Command failed with status: {}, stderr: {}", output.status, String::from_utf8_lossy(&output.stderr)));
-    }
-
-    let temp_str = str::from_utf8(&output.stdout).map_err(|e| format!("Failed to parse output: {}", e))?;
-    temp_str.trim().parse::<f32>().map_err(|e| format!("Failed to parse temperature: {}", e))
-}
-
-fn get_gpu_load() -> Result<f32, String> {
-    let output = Command::new("nvidia-smi")
-        .arg("--query-gpu=utilization.gpu")
-        .arg("--format=csv,noheader,nounits")
-        .output()
-        .map_err(|e| format!("Failed to execute command: {}", e))?;
-
-    if !output.status.success() {
-        return Err(format!("Command failed with status: {}, stderr: {}", output.status, String::from_utf8_lossy(&output.stderr)));
-    }
-
-    let load_str = str::from_utf8(&output.stdout).map_err(|e| format!("Failed to parse output: {}", e))?;
-    load_str.trim().parse::<f32>().map_err(|e| format!("Failed to parse load: {}", e))
-}
-
-fn get_gpu_model() -> Result<String, String> {
-    let output = Command::new("nvidia-smi")
-        .arg("--query-gpu=name")
-        .arg("--format=csv,noheader")
-        .output()
-        .map_err(|e| format!("Failed to execute command: {}", e))?;
+fn main() -> io::Result<()> {
+    init_logger();
 
-    if !output.status.success() {
-        return Err(format!("Command failed with status: {}, stderr: {}", output.status, String::from_utf8_lossy(&output.stderr)));
+    let config = AppConfig::load();
+    let fan_control_requested = std::env::args().any(|a| a == "--fan-control");
+    let gpu_filter = parse_gpu_filter();
+
+    let backend = gpu::detect_backend().unwrap_or_else(|e| {
+        error!("Failed to detect a GPU backend: {}", e);
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
+
+    if let Some(index) = gpu_filter {
+        let gpu_count = backend.sample_all().map(|samples| samples.len()).unwrap_or(0);
+        if index >= gpu_count {
+            error!("No GPU at index {} ({} GPU(s) detected)", index, gpu_count);
+            eprintln!("Error: no GPU at index {} ({} GPU(s) detected)", index, gpu_count);
+            std::process::exit(1);
+        }
     }
 
-    let model_str = str::from_utf8(&output.stdout).map_err(|e| format!("Failed to parse output: {}", e))?;
-    Ok(model_str.trim().to_string())
-}
+    let mut fan_controller = if fan_control_requested {
+        match FanController::detect(config.fan_curve(), gpu_filter.unwrap_or(0)) {
+            Ok(controller) => Some(controller),
+            Err(e) => {
+                error!("Failed to start fan control: {}", e);
+                eprintln!("Error: failed to start fan control: {}", e);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        None
+    };
 
-fn main() -> io::Result<()> {
-    init_logger();
     terminal::enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    stdout.execute(terminal::Clear(terminal::ClearType::All))?;
+    io::stdout().execute(terminal::EnterAlternateScreen)?;
+    let mut dashboard = Dashboard::new()?;
     info!("Starting gpu_temp_reader");
 
-    loop {
-        match get_gpu_temperature() {
-            Ok(temp) => {
-                let load = match get_gpu_load() {
-                    Ok(l) => l,
-                    Err(e) => {
-                        error!("Failed to get GPU load: {}", e);
-                        0.0
-                    }
+    'outer: loop {
+        match backend.sample_all() {
+            Ok(all_samples) => {
+                // Device indices line up with `samples` so a `--gpu N`
+                // filter still reports the real device index N, not 0.
+                let device_indices: Vec<usize> = match gpu_filter {
+                    Some(index) => vec![index],
+                    None => (0..all_samples.len()).collect(),
                 };
-                let model = match get_gpu_model() {
-                    Ok(m) => m,
-                    Err(e) => {
-                        error!("Failed to get GPU model: {}", e);
-                        "Unknown".to_string()
-                    }
+                let samples: Vec<_> = match gpu_filter {
+                    Some(index) => all_samples.into_iter().skip(index).take(1).collect(),
+                    None => all_samples,
                 };
-                info!("GPU: {} Temperature: {} °C, Load: {}%", model, temp, load);
-
-                if temp > 70.0 {
-                    stdout.execute(Print(
-                        format!("\rGPU: {} Temperature: {} °C, Load: {}%", model, temp.to_string().red(), load)
-                    ))?;
-                } else {
-                    stdout.execute(Print(
-                        format!("\rGPU: {} Temperature: {} °C, Load: {}%", model, temp, load)
-                    ))?;
+
+                for (&device_index, sample) in device_indices.iter().zip(samples.iter()) {
+                    info!(
+                        "GPU {}: {} Temperature: {} °C, Load: {}%, Memory: {}/{} MB, Power: {} W, Fan: {}%",
+                        device_index,
+                        sample.model,
+                        sample.temperature,
+                        sample.load,
+                        sample.memory_used_mb,
+                        sample.memory_total_mb,
+                        sample.power_draw_w,
+                        sample.fan_speed_percent
+                    );
                 }
 
-                stdout.flush()?;
+                if let Some(controller) = fan_controller.as_mut() {
+                    if let Some(sample) = samples.first() {
+                        if let Err(e) = controller.update(sample.temperature) {
+                            error!("Failed to update fan duty cycle: {}", e);
+                        }
+                    }
+                }
+
+                dashboard.record_samples(&device_indices, &samples);
+                dashboard.draw(&device_indices, &samples, &config)?;
             }
             Err(e) => {
-                error!("Failed to get GPU temperature: {}", e);
-                stdout.execute(Print(format!("\rError: {}", e)))?;
-                stdout.flush()?;
+                error!("Failed to sample GPU(s): {}", e);
             }
         }
 
-        if event::poll(Duration::from_secs(1))? {
-            if let event::Event::Key(event) = event::read()? {
-                if event.code == event::KeyCode::Char('q') {
-                    break;
-                }
+        if event::poll(Duration::from_secs(config.poll_interval_secs))? {
+            match event::read()? {
+                event::Event::Key(key) => match key.code {
+                    event::KeyCode::Char('q') => break 'outer,
+                    event::KeyCode::Char('?') => dashboard.toggle_help(),
+                    _ => {}
+                },
+                event::Event::Resize(_, _) => dashboard.handle_resize()?,
+                _ => {}
             }
         }
     }
 
+    if let Some(controller) = fan_controller.as_ref() {
+        if let Err(e) = controller.restore_auto() {
+            error!("Failed to restore automatic fan control: {}", e);
+        }
+    }
+
+    io::stdout().execute(terminal::LeaveAlternateScreen)?;
     terminal::disable_raw_mode()?;
-    println!("\nProgram terminated.");
     info!("Exiting gpu_temp_reader");
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,114 @@
+use serde::Deserialize;
+use std::path::PathBuf;
+
+use crate::error::ConfigError;
+use crate::fan::FanCurve;
+
+/// Which metrics to show in the live display.
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct MetricsConfig {
+    pub temperature: bool,
+    pub load: bool,
+    pub model: bool,
+    pub memory: bool,
+    pub power: bool,
+    pub fan_speed: bool,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        MetricsConfig {
+            temperature: true,
+            load: true,
+            model: true,
+            memory: true,
+            power: true,
+            fan_speed: true,
+        }
+    }
+}
+
+/// A single `(temperature, fan-percent)` point, as written in the config
+/// file. Converted to a [`FanCurve`] via [`Config::fan_curve`].
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct FanCurvePointConfig {
+    pub temp: f32,
+    pub percent: f32,
+}
+
+/// User-tunable settings, loaded from `$XDG_CONFIG_HOME/gpu_temp_reader/config.toml`
+/// (or sensible defaults if the file is absent or fails to parse).
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub warning_temp: f32,
+    pub critical_temp: f32,
+    pub poll_interval_secs: u64,
+    pub metrics: MetricsConfig,
+    pub fan_curve: Vec<FanCurvePointConfig>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            warning_temp: 60.0,
+            critical_temp: 70.0,
+            poll_interval_secs: 1,
+            metrics: MetricsConfig::default(),
+            fan_curve: Vec::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads the config from [`config_path`]. Returns [`ConfigError::Io`] if
+    /// the file can't be read (including if it's simply absent) and
+    /// [`ConfigError::Parse`] if it exists but isn't valid TOML, so callers
+    /// can distinguish "nothing configured" from "configured wrong".
+    pub fn try_load() -> Result<Self, ConfigError> {
+        let path = config_path();
+
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| ConfigError::Io { path: path.display().to_string(), source: e })?;
+
+        toml::from_str(&contents).map_err(|e| ConfigError::Parse { path: path.display().to_string(), source: e })
+    }
+
+    /// Loads the config from [`config_path`], falling back to
+    /// [`Config::default`] if the file is missing or fails to parse. Parse
+    /// failures (but not a simply-absent file) are logged as a warning.
+    pub fn load() -> Self {
+        match Self::try_load() {
+            Ok(config) => config,
+            Err(e @ ConfigError::Parse { .. }) => {
+                log::warn!("{}, using defaults", e);
+                Config::default()
+            }
+            Err(ConfigError::Io { .. }) => Config::default(),
+        }
+    }
+
+    /// Builds a [`FanCurve`] from the configured points, falling back to
+    /// [`FanCurve::default_curve`] if none were configured.
+    pub fn fan_curve(&self) -> FanCurve {
+        if self.fan_curve.is_empty() {
+            return FanCurve::default_curve();
+        }
+
+        FanCurve::new(self.fan_curve.iter().map(|p| (p.temp, p.percent)).collect())
+    }
+}
+
+/// The standard config file location: `$XDG_CONFIG_HOME/gpu_temp_reader/config.toml`,
+/// falling back to `~/.config/gpu_temp_reader/config.toml`.
+pub fn config_path() -> PathBuf {
+    let config_home = std::env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| {
+            let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
+            PathBuf::from(home).join(".config")
+        });
+
+    config_home.join("gpu_temp_reader").join("config.toml")
+}
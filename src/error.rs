@@ -0,0 +1,54 @@
+use std::io;
+use std::num::ParseFloatError;
+use std::process::ExitStatus;
+use std::str::Utf8Error;
+
+use thiserror::Error;
+
+/// Errors produced by a [`crate::gpu::GpuBackend`] while querying GPU
+/// telemetry, whether via a vendor CLI tool or sysfs, and by
+/// [`crate::fan::FanController`] while writing fan sysfs nodes.
+#[derive(Debug, Error)]
+pub enum GpuError {
+    #[error("failed to spawn command: {0}")]
+    CommandSpawn(#[source] io::Error),
+
+    #[error("command failed with status {status}: {stderr}")]
+    CommandFailed { status: ExitStatus, stderr: String },
+
+    #[error("failed to access {path}: {source}")]
+    Io { path: String, #[source] source: io::Error },
+
+    #[error("failed to decode command output as UTF-8: {0}")]
+    Utf8(#[source] Utf8Error),
+
+    #[error("failed to parse numeric value: {0}")]
+    Parse(#[source] ParseFloatError),
+
+    #[error("malformed backend output: {0}")]
+    MalformedOutput(String),
+
+    #[error("no supported GPU backend found")]
+    NoBackend,
+
+    #[error("no GPU at index {0}")]
+    NoSuchDevice(usize),
+}
+
+/// Errors produced while loading the config file.
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("failed to read config file {path}: {source}")]
+    Io {
+        path: String,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("failed to parse config file {path}: {source}")]
+    Parse {
+        path: String,
+        #[source]
+        source: toml::de::Error,
+    },
+}
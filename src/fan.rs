@@ -0,0 +1,142 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::error::GpuError;
+use crate::gpu::AmdBackend;
+
+/// A single (temperature, fan-percent) point on a fan curve.
+#[derive(Debug, Clone, Copy)]
+pub struct FanCurvePoint {
+    pub temp: f32,
+    pub percent: f32,
+}
+
+/// A sorted set of (temperature, fan-percent) points, linearly interpolated
+/// between neighbours and clamped at the ends.
+#[derive(Debug, Clone)]
+pub struct FanCurve {
+    points: Vec<FanCurvePoint>,
+}
+
+impl FanCurve {
+    /// Builds a curve from `(temp, percent)` pairs, sorting them by
+    /// temperature so `duty_for` can assume an ascending order.
+    pub fn new(mut points: Vec<(f32, f32)>) -> Self {
+        points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        FanCurve {
+            points: points.into_iter().map(|(temp, percent)| FanCurvePoint { temp, percent }).collect(),
+        }
+    }
+
+    /// A reasonable default curve for a quiet-until-warm workstation GPU.
+    pub fn default_curve() -> Self {
+        FanCurve::new(vec![(40.0, 20.0), (60.0, 40.0), (75.0, 70.0), (85.0, 100.0)])
+    }
+
+    /// Interpolates the fan duty cycle for a given temperature, clamping to
+    /// the first point's percent below the curve and the last point's
+    /// percent above it.
+    pub fn duty_for(&self, temp: f32) -> f32 {
+        let points = &self.points;
+        if points.is_empty() {
+            return 0.0;
+        }
+
+        if temp <= points[0].temp {
+            return points[0].percent;
+        }
+        if temp >= points[points.len() - 1].temp {
+            return points[points.len() - 1].percent;
+        }
+
+        for pair in points.windows(2) {
+            let (lo, hi) = (pair[0], pair[1]);
+            if temp >= lo.temp && temp < hi.temp {
+                let t = (temp - lo.temp) / (hi.temp - lo.temp);
+                return lo.percent + (hi.percent - lo.percent) * t;
+            }
+        }
+
+        points[points.len() - 1].percent
+    }
+}
+
+/// How far the temperature must move since the last write before the fan
+/// duty cycle is updated again, to avoid oscillating near a curve step.
+const HYSTERESIS_DEGREES: f32 = 2.0;
+
+/// Drives an AMD GPU's `pwm1` sysfs node according to a [`FanCurve`].
+pub struct FanController {
+    hwmon_dir: PathBuf,
+    curve: FanCurve,
+    last_written_temp: Option<f32>,
+}
+
+impl FanController {
+    /// Detects the hwmon directory for the AMD GPU at `gpu_index` and
+    /// switches its fan into manual mode. Call [`FanController::restore_auto`]
+    /// on exit.
+    pub fn detect(curve: FanCurve, gpu_index: usize) -> Result<Self, GpuError> {
+        let backend = AmdBackend::detect().ok_or(GpuError::NoBackend)?;
+        let hwmon_dir = backend.hwmon_dir(gpu_index).ok_or(GpuError::NoSuchDevice(gpu_index))?.to_path_buf();
+
+        let path = hwmon_dir.join("pwm1_enable");
+        fs::write(&path, "1").map_err(|e| GpuError::Io { path: path.display().to_string(), source: e })?;
+
+        Ok(FanController { hwmon_dir, curve, last_written_temp: None })
+    }
+
+    /// Recomputes the duty cycle for the current temperature and writes it
+    /// to `pwm1` if it has moved far enough from the last written value.
+    pub fn update(&mut self, temp: f32) -> Result<(), GpuError> {
+        if let Some(last) = self.last_written_temp {
+            if (temp - last).abs() < HYSTERESIS_DEGREES {
+                return Ok(());
+            }
+        }
+
+        let percent = self.curve.duty_for(temp);
+        let pwm_value = (percent / 100.0 * 255.0).round() as u8;
+
+        let path = self.hwmon_dir.join("pwm1");
+        fs::write(&path, pwm_value.to_string()).map_err(|e| GpuError::Io { path: path.display().to_string(), source: e })?;
+
+        self.last_written_temp = Some(temp);
+        Ok(())
+    }
+
+    /// Restores automatic fan control. Should be called before the process
+    /// exits so the card isn't left stuck at the last manual duty cycle.
+    pub fn restore_auto(&self) -> Result<(), GpuError> {
+        let path = self.hwmon_dir.join("pwm1_enable");
+        fs::write(&path, "2").map_err(|e| GpuError::Io { path: path.display().to_string(), source: e })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve() -> FanCurve {
+        FanCurve::new(vec![(40.0, 20.0), (60.0, 40.0), (75.0, 70.0), (85.0, 100.0)])
+    }
+
+    #[test]
+    fn interpolates_between_bracketing_points() {
+        let curve = curve();
+        // Halfway between (60.0, 40.0) and (75.0, 70.0).
+        assert_eq!(curve.duty_for(67.5), 55.0);
+    }
+
+    #[test]
+    fn clamps_below_first_point() {
+        let curve = curve();
+        assert_eq!(curve.duty_for(10.0), 20.0);
+    }
+
+    #[test]
+    fn clamps_above_last_point() {
+        let curve = curve();
+        assert_eq!(curve.duty_for(100.0), 100.0);
+    }
+}
@@ -0,0 +1,149 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{GpuBackend, GpuSample};
+use crate::error::GpuError;
+
+const AMD_VENDOR_ID: &str = "0x1002";
+
+/// A small lookup table of PCI device ids we know the marketing name for.
+/// Not exhaustive — AMD doesn't publish a machine-readable id-to-name
+/// mapping the way `nvidia-smi` does, so anything not listed here falls
+/// back to a generic name that includes the raw id.
+const KNOWN_DEVICE_IDS: &[(&str, &str)] = &[
+    ("0x73bf", "AMD Radeon RX 6900 XT"),
+    ("0x73df", "AMD Radeon RX 6700 XT"),
+    ("0x7483", "AMD Radeon RX 7600"),
+    ("0x744c", "AMD Radeon RX 7900 XTX"),
+    ("0x15e7", "AMD Radeon 680M (integrated)"),
+];
+
+/// Resolves a PCI device id (e.g. `"0x73bf"`) to a marketing name, falling
+/// back to `"AMD GPU (PCI device <id>)"` when it isn't in [`KNOWN_DEVICE_IDS`].
+fn resolve_model_name(device_id: &str) -> String {
+    KNOWN_DEVICE_IDS
+        .iter()
+        .find(|(id, _)| *id == device_id)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or_else(|| format!("AMD GPU (PCI device {})", device_id))
+}
+
+/// The sysfs paths backing a single AMD card.
+struct AmdCard {
+    /// `/sys/class/drm/cardN/device`
+    device_dir: PathBuf,
+    /// `/sys/class/drm/cardN/device/hwmon/hwmonM`
+    hwmon_dir: PathBuf,
+}
+
+/// Reads GPU telemetry straight from the kernel's sysfs hwmon nodes, which
+/// is what AMD's amdgpu driver exposes in lieu of a vendor CLI tool. Holds
+/// one entry per AMD card found under `/sys/class/drm`, in card order.
+pub struct AmdBackend {
+    cards: Vec<AmdCard>,
+}
+
+impl AmdBackend {
+    /// Scans `/sys/class/drm/card*` for every AMD card with a hwmon
+    /// directory, or returns `None` if this machine has no AMD GPU.
+    pub fn detect() -> Option<Self> {
+        let mut entries: Vec<_> = fs::read_dir("/sys/class/drm").ok()?.flatten().collect();
+        entries.sort_by_key(|e| e.file_name());
+
+        let mut cards = Vec::new();
+        for entry in entries {
+            let path = entry.path();
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if !name.starts_with("card") || name.contains('-') {
+                continue;
+            }
+
+            let device_dir = path.join("device");
+            let is_amd = fs::read_to_string(device_dir.join("vendor"))
+                .map(|v| v.trim() == AMD_VENDOR_ID)
+                .unwrap_or(false);
+            if !is_amd {
+                continue;
+            }
+
+            if let Some(hwmon_dir) = find_hwmon_dir(&device_dir) {
+                cards.push(AmdCard { device_dir, hwmon_dir });
+            }
+        }
+
+        if cards.is_empty() {
+            None
+        } else {
+            Some(AmdBackend { cards })
+        }
+    }
+
+    fn read_file(dir: &Path, name: &str) -> Result<String, GpuError> {
+        let path = dir.join(name);
+        fs::read_to_string(&path)
+            .map_err(|e| GpuError::Io { path: path.display().to_string(), source: e })
+    }
+
+    /// Reads a numeric sysfs file, returning `default` if it doesn't exist
+    /// (some nodes, like `power1_average`, aren't present on every card).
+    fn read_optional_f32(dir: &Path, name: &str, default: f32) -> Result<f32, GpuError> {
+        match fs::read_to_string(dir.join(name)) {
+            Ok(contents) => contents.trim().parse::<f32>().map_err(GpuError::Parse),
+            Err(_) => Ok(default),
+        }
+    }
+
+    fn sample_card(card: &AmdCard) -> Result<GpuSample, GpuError> {
+        let millidegrees = Self::read_file(&card.hwmon_dir, "temp1_input")?;
+        let temperature = millidegrees.trim().parse::<f32>().map_err(GpuError::Parse)? / 1000.0;
+
+        let load = Self::read_file(&card.device_dir, "gpu_busy_percent")?
+            .trim()
+            .parse::<f32>()
+            .map_err(GpuError::Parse)?;
+
+        let device_id = Self::read_file(&card.device_dir, "device")?;
+        let model = resolve_model_name(device_id.trim());
+
+        let memory_used_mb = Self::read_optional_f32(&card.device_dir, "mem_info_vram_used", 0.0)? / (1024.0 * 1024.0);
+        let memory_total_mb = Self::read_optional_f32(&card.device_dir, "mem_info_vram_total", 0.0)? / (1024.0 * 1024.0);
+        let power_draw_w = Self::read_optional_f32(&card.hwmon_dir, "power1_average", 0.0)? / 1_000_000.0;
+
+        // amdgpu doesn't expose a fan percentage directly; approximate it
+        // from the manual PWM duty cycle (0-255) when one has been set.
+        let pwm = Self::read_optional_f32(&card.hwmon_dir, "pwm1", 0.0)?;
+        let fan_speed_percent = pwm / 255.0 * 100.0;
+
+        Ok(GpuSample {
+            model,
+            temperature,
+            load,
+            memory_used_mb,
+            memory_total_mb,
+            power_draw_w,
+            fan_speed_percent,
+        })
+    }
+
+    /// The `hwmon*` directory for the card at `index`, e.g. for fan control.
+    pub fn hwmon_dir(&self, index: usize) -> Option<&Path> {
+        self.cards.get(index).map(|c| c.hwmon_dir.as_path())
+    }
+}
+
+/// Finds the `hwmon*` subdirectory under a card's device directory.
+fn find_hwmon_dir(device_dir: &Path) -> Option<PathBuf> {
+    let hwmon_root = device_dir.join("hwmon");
+    let entries = fs::read_dir(hwmon_root).ok()?;
+    entries
+        .flatten()
+        .map(|e| e.path())
+        .find(|p| p.file_name().map(|n| n.to_string_lossy().starts_with("hwmon")).unwrap_or(false))
+}
+
+impl GpuBackend for AmdBackend {
+    fn sample_all(&self) -> Result<Vec<GpuSample>, GpuError> {
+        self.cards.iter().map(Self::sample_card).collect()
+    }
+}
@@ -0,0 +1,44 @@
+mod nvidia;
+mod amd;
+
+pub use amd::AmdBackend;
+pub use nvidia::NvidiaBackend;
+
+use crate::error::GpuError;
+
+/// A single point-in-time snapshot of everything we know about one GPU.
+/// The NVIDIA backend fetches it in one batched query rather than one call
+/// per field; the AMD backend reads one sysfs file per field.
+#[derive(Debug, Clone, Default)]
+pub struct GpuSample {
+    pub model: String,
+    pub temperature: f32,
+    pub load: f32,
+    pub memory_used_mb: f32,
+    pub memory_total_mb: f32,
+    pub power_draw_w: f32,
+    pub fan_speed_percent: f32,
+}
+
+/// A source of GPU telemetry. Implementations talk to a specific vendor's
+/// tooling or kernel interface (e.g. `nvidia-smi`, AMD's sysfs hwmon nodes)
+/// so the rest of the crate can stay vendor-agnostic.
+pub trait GpuBackend {
+    /// Fetches a sample for every GPU this backend can see, one entry per
+    /// device, in device order.
+    fn sample_all(&self) -> Result<Vec<GpuSample>, GpuError>;
+}
+
+/// Picks the first available backend, preferring NVIDIA since `nvidia-smi`
+/// is the most common case, then falling back to AMD's sysfs hwmon nodes.
+pub fn detect_backend() -> Result<Box<dyn GpuBackend>, GpuError> {
+    if NvidiaBackend::is_available() {
+        return Ok(Box::new(NvidiaBackend::new()));
+    }
+
+    if let Some(backend) = AmdBackend::detect() {
+        return Ok(Box::new(backend));
+    }
+
+    Err(GpuError::NoBackend)
+}
@@ -0,0 +1,84 @@
+use std::process::Command;
+use std::str;
+
+use super::{GpuBackend, GpuSample};
+use crate::error::GpuError;
+
+const QUERY_FIELDS: &str = "name,temperature.gpu,utilization.gpu,memory.used,memory.total,power.draw,fan.speed";
+
+/// Talks to `nvidia-smi`, fetching every metric for every visible card in
+/// one combined query (one CSV line per GPU).
+pub struct NvidiaBackend;
+
+impl NvidiaBackend {
+    pub fn new() -> Self {
+        NvidiaBackend
+    }
+
+    /// Returns true if `nvidia-smi` is present and responds to a query.
+    pub fn is_available() -> bool {
+        Command::new("nvidia-smi")
+            .arg("--query-gpu=name")
+            .arg("--format=csv,noheader")
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+}
+
+/// Parses a numeric CSV field, treating any bracketed placeholder —
+/// `[N/A]`, `[Not Supported]`, `[Insufficient Permissions]`, etc., which
+/// `nvidia-smi` emits for `power.draw`/`fan.speed` on cards that don't
+/// report them (common on passively-cooled datacenter GPUs) — plus bare
+/// `n/a` and empty fields, as `0.0` rather than failing the whole sample.
+fn parse_f32_or_zero(field: &str) -> Result<f32, GpuError> {
+    let field = field.trim();
+    let is_placeholder = field.is_empty()
+        || field.eq_ignore_ascii_case("n/a")
+        || (field.starts_with('[') && field.ends_with(']'));
+    if is_placeholder {
+        return Ok(0.0);
+    }
+    field.parse::<f32>().map_err(GpuError::Parse)
+}
+
+fn parse_row(row: &str) -> Result<GpuSample, GpuError> {
+    let fields: Vec<&str> = row.split(',').collect();
+    if fields.len() != 7 {
+        return Err(GpuError::MalformedOutput(format!(
+            "expected 7 CSV fields, got {}: {:?}",
+            fields.len(),
+            row
+        )));
+    }
+
+    Ok(GpuSample {
+        model: fields[0].trim().to_string(),
+        temperature: parse_f32_or_zero(fields[1])?,
+        load: parse_f32_or_zero(fields[2])?,
+        memory_used_mb: parse_f32_or_zero(fields[3])?,
+        memory_total_mb: parse_f32_or_zero(fields[4])?,
+        power_draw_w: parse_f32_or_zero(fields[5])?,
+        fan_speed_percent: parse_f32_or_zero(fields[6])?,
+    })
+}
+
+impl GpuBackend for NvidiaBackend {
+    fn sample_all(&self) -> Result<Vec<GpuSample>, GpuError> {
+        let output = Command::new("nvidia-smi")
+            .arg(format!("--query-gpu={}", QUERY_FIELDS))
+            .arg("--format=csv,noheader,nounits")
+            .output()
+            .map_err(GpuError::CommandSpawn)?;
+
+        if !output.status.success() {
+            return Err(GpuError::CommandFailed {
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+            });
+        }
+
+        let text = str::from_utf8(&output.stdout).map_err(GpuError::Utf8)?;
+        text.lines().filter(|line| !line.trim().is_empty()).map(parse_row).collect()
+    }
+}
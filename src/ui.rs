@@ -0,0 +1,243 @@
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, Stdout};
+
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Sparkline};
+use ratatui::{Frame, Terminal};
+
+use crate::config::Config;
+use crate::gpu::GpuSample;
+
+/// How many samples of history to keep per metric for the sparkline graphs.
+const HISTORY_CAPACITY: usize = 120;
+
+/// A fixed-size ring buffer of recent samples for each displayed metric of
+/// one GPU.
+pub struct History {
+    capacity: usize,
+    pub temperature: VecDeque<f32>,
+    pub load: VecDeque<f32>,
+    pub memory_used_mb: VecDeque<f32>,
+    pub power_draw_w: VecDeque<f32>,
+    pub fan_speed_percent: VecDeque<f32>,
+}
+
+impl History {
+    pub fn new(capacity: usize) -> Self {
+        History {
+            capacity,
+            temperature: VecDeque::with_capacity(capacity),
+            load: VecDeque::with_capacity(capacity),
+            memory_used_mb: VecDeque::with_capacity(capacity),
+            power_draw_w: VecDeque::with_capacity(capacity),
+            fan_speed_percent: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn record(&mut self, sample: &GpuSample) {
+        push_bounded(&mut self.temperature, sample.temperature, self.capacity);
+        push_bounded(&mut self.load, sample.load, self.capacity);
+        push_bounded(&mut self.memory_used_mb, sample.memory_used_mb, self.capacity);
+        push_bounded(&mut self.power_draw_w, sample.power_draw_w, self.capacity);
+        push_bounded(&mut self.fan_speed_percent, sample.fan_speed_percent, self.capacity);
+    }
+}
+
+fn push_bounded(buffer: &mut VecDeque<f32>, value: f32, capacity: usize) {
+    if buffer.len() == capacity {
+        buffer.pop_front();
+    }
+    buffer.push_back(value);
+}
+
+/// The terminal dashboard: one column of sparkline history graphs per GPU,
+/// plus a toggleable `?` help overlay. Histories are keyed by the device's
+/// real index (as reported by the backend, not its position in a
+/// `--gpu`-filtered slice) so history survives the filter changing which
+/// devices are shown.
+pub struct Dashboard {
+    terminal: Terminal<CrosstermBackend<Stdout>>,
+    histories: HashMap<usize, History>,
+    show_help: bool,
+}
+
+impl Dashboard {
+    pub fn new() -> io::Result<Self> {
+        let backend = CrosstermBackend::new(io::stdout());
+        let terminal = Terminal::new(backend)?;
+        Ok(Dashboard { terminal, histories: HashMap::new(), show_help: false })
+    }
+
+    pub fn record_samples(&mut self, device_indices: &[usize], samples: &[GpuSample]) {
+        for (&device_index, sample) in device_indices.iter().zip(samples.iter()) {
+            self.histories.entry(device_index).or_insert_with(|| History::new(HISTORY_CAPACITY)).record(sample);
+        }
+    }
+
+    pub fn toggle_help(&mut self) {
+        self.show_help = !self.show_help;
+    }
+
+    /// Re-reads the terminal size. Called on `Event::Resize` so the next
+    /// draw lays out against the new dimensions.
+    pub fn handle_resize(&mut self) -> io::Result<()> {
+        self.terminal.autoresize()
+    }
+
+    pub fn draw(&mut self, device_indices: &[usize], samples: &[GpuSample], config: &Config) -> io::Result<()> {
+        let histories = &self.histories;
+        let show_help = self.show_help;
+        self.terminal.draw(|frame| render(frame, device_indices, samples, histories, config, show_help))?;
+        Ok(())
+    }
+}
+
+/// One sparkline row and whether it's enabled in the config.
+struct Row<'a> {
+    enabled: bool,
+    title: String,
+    history: &'a VecDeque<f32>,
+    color: Color,
+}
+
+fn render(
+    frame: &mut Frame,
+    device_indices: &[usize],
+    samples: &[GpuSample],
+    histories: &HashMap<usize, History>,
+    config: &Config,
+    show_help: bool,
+) {
+    let area = frame.size();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+
+    let header = Paragraph::new(Line::from(vec![
+        Span::styled("gpu_temp_reader", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!("  {} GPU(s)  (press ? for help, q to quit)", samples.len())),
+    ]))
+    .block(Block::default().borders(Borders::ALL).title("gpu_temp_reader"));
+    frame.render_widget(header, chunks[0]);
+
+    if !samples.is_empty() {
+        let percent = 100 / samples.len() as u16;
+        let constraints: Vec<Constraint> = samples.iter().map(|_| Constraint::Percentage(percent)).collect();
+        let columns = Layout::default().direction(Direction::Horizontal).constraints(constraints).split(chunks[1]);
+
+        for (column, (&device_index, sample)) in columns.iter().zip(device_indices.iter().zip(samples.iter())) {
+            if let Some(history) = histories.get(&device_index) {
+                render_device(frame, *column, device_index, sample, history, config);
+            }
+        }
+    }
+
+    let status = Paragraph::new("q: quit  ?: help");
+    frame.render_widget(status, chunks[2]);
+
+    if show_help {
+        render_help_overlay(frame, area);
+    }
+}
+
+fn render_device(frame: &mut Frame, area: Rect, device_index: usize, sample: &GpuSample, history: &History, config: &Config) {
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(area);
+
+    let title = if config.metrics.model {
+        format!("GPU {}: {}", device_index, sample.model)
+    } else {
+        format!("GPU {}", device_index)
+    };
+    frame.render_widget(Paragraph::new(Span::styled(title, Style::default().add_modifier(Modifier::BOLD))), sections[0]);
+
+    let rows = [
+        Row {
+            enabled: config.metrics.temperature,
+            title: "Temperature (°C)".to_string(),
+            history: &history.temperature,
+            color: temperature_color(sample.temperature, config),
+        },
+        Row { enabled: config.metrics.load, title: "Load (%)".to_string(), history: &history.load, color: Color::Cyan },
+        Row {
+            enabled: config.metrics.memory,
+            title: format!("Memory used (MB of {:.0})", sample.memory_total_mb),
+            history: &history.memory_used_mb,
+            color: Color::Magenta,
+        },
+        Row {
+            enabled: config.metrics.power,
+            title: "Power draw (W)".to_string(),
+            history: &history.power_draw_w,
+            color: Color::Yellow,
+        },
+        Row {
+            enabled: config.metrics.fan_speed,
+            title: "Fan speed (%)".to_string(),
+            history: &history.fan_speed_percent,
+            color: Color::Green,
+        },
+    ];
+    let active_rows: Vec<&Row> = rows.iter().filter(|r| r.enabled).collect();
+
+    if active_rows.is_empty() {
+        return;
+    }
+
+    let percent = 100 / active_rows.len() as u16;
+    let constraints: Vec<Constraint> = active_rows.iter().map(|_| Constraint::Percentage(percent)).collect();
+    let areas = Layout::default().direction(Direction::Vertical).constraints(constraints).split(sections[1]);
+
+    for (area, row) in areas.iter().zip(active_rows.iter()) {
+        render_sparkline(frame, *area, &row.title, row.history, row.color);
+    }
+}
+
+/// Colors the temperature row green/yellow/red against the configured
+/// warning and critical thresholds, mirroring the old single-line display.
+fn temperature_color(temperature: f32, config: &Config) -> Color {
+    if temperature > config.critical_temp {
+        Color::Red
+    } else if temperature > config.warning_temp {
+        Color::Yellow
+    } else {
+        Color::Green
+    }
+}
+
+fn render_sparkline(frame: &mut Frame, area: Rect, title: &str, history: &VecDeque<f32>, color: Color) {
+    let data: Vec<u64> = history.iter().map(|v| v.round().max(0.0) as u64).collect();
+    let sparkline = Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .data(&data)
+        .style(Style::default().fg(color));
+    frame.render_widget(sparkline, area);
+}
+
+fn render_help_overlay(frame: &mut Frame, area: Rect) {
+    let width = (area.width.saturating_sub(4)).min(40);
+    let height = 7u16.min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width.saturating_sub(width)) / 2,
+        y: area.y + (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+
+    let help_text = Paragraph::new(vec![
+        Line::from("Keybindings"),
+        Line::from(""),
+        Line::from("q        quit"),
+        Line::from("?        toggle this help"),
+    ])
+    .block(Block::default().borders(Borders::ALL).title("Help"));
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(help_text, popup);
+}